@@ -7,54 +7,36 @@
 use std::ops::{Index, IndexMut};
 use vec2d_error::Vec2dError;
 pub mod vec2d_error;
+pub mod rect;
+pub use rect::Rect;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "numeric")]
+mod numeric;
 pub type Pos = (usize, usize);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-/// 
-pub struct Vec2d<T: Clone> {
+///
+pub struct Vec2d<T> {
     tiles: Vec<T>,
     width: usize
 }
 
-impl<T: Clone> Index<Pos> for Vec2d<T> {
+impl<T> Index<Pos> for Vec2d<T> {
     type Output = T;
 
-    fn index(&self, (x, y): Pos) -> &Self::Output {
-        if x >= self.width { panic!("Tried to index with x: {}, with width: {}", x, self.width) }
-        &self.tiles[y * self.width + x]
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.try_index(pos).unwrap_or_else(|e| panic!("{}", e))
     }
 }
 
-impl<T: Clone> IndexMut<Pos> for Vec2d<T> {
-    fn index_mut(&mut self, (x, y): Pos) -> &mut Self::Output {
-        if x >= self.width { panic!("Tried to index with x: {}, with width: {}", x, self.width) }
-        &mut self.tiles[y * self.width + x]
+impl<T> IndexMut<Pos> for Vec2d<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.try_index_mut(pos).unwrap_or_else(|e| panic!("{}", e))
     }
 }
 
-impl<T: Clone> Vec2d<T> {
-    /// Create a new `Vec2d`
-    /// # Examples
-    /// ```
-    /// let board = board::Vec2d::new('a', 2, 3).unwrap();
-    /// assert_eq!(board.tiles(), &vec!['a','a','a','a','a','a']);
-    /// assert_eq!(board.width(), 2);
-    /// assert_eq!(board.height(), 3);
-    /// ```
-    pub fn new(default: T, width: usize, height: usize) -> Result<Vec2d<T>, Vec2dError> {
-        let no_tiles = width * height;
-        if no_tiles == 0 {
-            Err(Vec2dError::WidthOrHeightIs0{ width, height })
-        } else {
-            let mut tiles = Vec::with_capacity(no_tiles);
-            for _ in 1..no_tiles {
-                tiles.push(default.clone());
-            } 
-            tiles.push(default);
-            Ok(Vec2d { tiles, width })
-        }
-    }
-
+impl<T> Vec2d<T> {
     /// Create a new `Vec2d` from an existing `Vec`.
     /// Moves the original vec into a `Vec2d` without copying/cloning.
     /// # Examples
@@ -130,6 +112,30 @@ impl<T: Clone> Vec2d<T> {
         self.tiles.get_mut(y * self.width + x)
     }
 
+    /// Get a `&Tile` at `pos`, checking both `x` and `y` against the bounds of the `Vec2d`.
+    /// Returns a `Vec2dError::OutOfBounds` instead of panicking, unlike indexing with `[]`.
+    /// # Examples
+    /// ```
+    /// let board = board::Vec2d::new('a', 2, 3).unwrap();
+    /// assert_eq!(board.try_index((0, 0)).unwrap(), &'a');
+    /// assert!(board.try_index((0, 3)).is_err());
+    /// ```
+    pub fn try_index(&self, (x, y): Pos) -> Result<&T, Vec2dError> {
+        if x >= self.width || y >= self.height() {
+            return Err(Vec2dError::OutOfBounds { pos: (x, y), width: self.width, height: self.height() });
+        }
+        Ok(&self.tiles[y * self.width + x])
+    }
+
+    /// Get a `&mut Tile` at `pos`, checking both `x` and `y` against the bounds of the `Vec2d`.
+    /// Returns a `Vec2dError::OutOfBounds` instead of panicking, unlike indexing with `[]`.
+    pub fn try_index_mut(&mut self, (x, y): Pos) -> Result<&mut T, Vec2dError> {
+        if x >= self.width || y >= self.height() {
+            return Err(Vec2dError::OutOfBounds { pos: (x, y), width: self.width, height: self.height() });
+        }
+        Ok(&mut self.tiles[y * self.width + x])
+    }
+
     pub fn get_row(&self, y: usize) -> Option<&[T]> {
         if y >= self.tiles.len() / self.width { return None }
         Some(&self.tiles[y * self.width .. (y+1) * self.width])
@@ -140,6 +146,18 @@ impl<T: Clone> Vec2d<T> {
         Some(&mut self.tiles[y * self.width .. (y+1) * self.width])
     }
 
+    /// Get a `Vec<&Tile>` of column `x`
+    /// # Examples
+    /// ```
+    /// let board = board::Vec2d::new_from_vec(vec![1, 2, 3, 4, 5, 6], 2).unwrap();
+    /// assert_eq!(board.get_col(1), Some(vec![&2, &4, &6]));
+    /// assert_eq!(board.get_col(2), None);
+    /// ```
+    pub fn get_col(&self, x: usize) -> Option<Vec<&T>> {
+        if x >= self.width { return None }
+        Some((0..self.height()).map(|y| &self.tiles[y * self.width + x]).collect())
+    }
+
     /// Get an iterator over all `(x, y)` values: `Iterator<Item = (usize, Iterator<Item = usize>)>`
     /// # Examples
     /// ```
@@ -215,6 +233,151 @@ impl<T: Clone> Vec2d<T> {
     pub fn to_vec(self) -> Vec<T> {
         self.tiles
     }
+
+    /// Create a new `Vec2d` by calling `f(x, y)` once for each cell, in row-major order,
+    /// and storing its return value at that position.
+    /// Unlike `new`, this does not require `T: Clone`.
+    /// # Examples
+    /// ```
+    /// let board = board::Vec2d::new_from_fn(2, 3, |x, y| x + y).unwrap();
+    /// assert_eq!(board[(1, 2)], 3);
+    /// assert_eq!(board.width(), 2);
+    /// assert_eq!(board.height(), 3);
+    /// ```
+    pub fn new_from_fn<F: FnMut(usize, usize) -> T>(
+        width: usize,
+        height: usize,
+        mut f: F,
+    ) -> Result<Vec2d<T>, Vec2dError> {
+        let no_tiles = width * height;
+        if no_tiles == 0 {
+            Err(Vec2dError::WidthOrHeightIs0 { width, height })
+        } else {
+            let mut tiles = Vec::with_capacity(no_tiles);
+            for y in 0..height {
+                for x in 0..width {
+                    tiles.push(f(x, y));
+                }
+            }
+            Ok(Vec2d { tiles, width })
+        }
+    }
+}
+
+impl<T: Clone> Vec2d<T> {
+    /// Create a new `Vec2d`
+    /// # Examples
+    /// ```
+    /// let board = board::Vec2d::new('a', 2, 3).unwrap();
+    /// assert_eq!(board.tiles(), &vec!['a','a','a','a','a','a']);
+    /// assert_eq!(board.width(), 2);
+    /// assert_eq!(board.height(), 3);
+    /// ```
+    pub fn new(default: T, width: usize, height: usize) -> Result<Vec2d<T>, Vec2dError> {
+        let no_tiles = width * height;
+        if no_tiles == 0 {
+            Err(Vec2dError::WidthOrHeightIs0{ width, height })
+        } else {
+            let mut tiles = Vec::with_capacity(no_tiles);
+            for _ in 1..no_tiles {
+                tiles.push(default.clone());
+            }
+            tiles.push(default);
+            Ok(Vec2d { tiles, width })
+        }
+    }
+
+    /// Returns a new `Vec2d` with width and height swapped, where `(x, y)` becomes `(y, x)`.
+    /// # Examples
+    /// ```
+    /// let board = board::Vec2d::new_from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+    /// let transposed = board.transpose();
+    /// assert_eq!(transposed.width(), 2);
+    /// assert_eq!(transposed.height(), 3);
+    /// assert_eq!(transposed[(1, 2)], board[(2, 1)]);
+    /// ```
+    pub fn transpose(&self) -> Vec2d<T> {
+        let new_width = self.height();
+        let new_height = self.width;
+        let mut tiles = Vec::with_capacity(self.tiles.len());
+        for ny in 0..new_height {
+            for nx in 0..new_width {
+                tiles.push(self[(ny, nx)].clone());
+            }
+        }
+        Vec2d { tiles, width: new_width }
+    }
+
+    /// Rotates the grid 90 degrees clockwise. A `w`x`h` grid becomes a `h`x`w` grid.
+    /// # Examples
+    /// ```
+    /// let board = board::Vec2d::new_from_vec(vec![1, 2, 3, 4], 2).unwrap();
+    /// let rotated = board.rotate_cw();
+    /// assert_eq!(rotated.to_vec(), vec![3, 1, 4, 2]);
+    /// ```
+    pub fn rotate_cw(&self) -> Vec2d<T> {
+        let old_height = self.height();
+        let new_width = old_height;
+        let new_height = self.width;
+        let mut tiles = Vec::with_capacity(self.tiles.len());
+        for ny in 0..new_height {
+            for nx in 0..new_width {
+                tiles.push(self[(ny, old_height - 1 - nx)].clone());
+            }
+        }
+        Vec2d { tiles, width: new_width }
+    }
+
+    /// Rotates the grid 90 degrees counter-clockwise. A `w`x`h` grid becomes a `h`x`w` grid.
+    /// # Examples
+    /// ```
+    /// let board = board::Vec2d::new_from_vec(vec![1, 2, 3, 4], 2).unwrap();
+    /// let rotated = board.rotate_ccw();
+    /// assert_eq!(rotated.to_vec(), vec![2, 4, 1, 3]);
+    /// ```
+    pub fn rotate_ccw(&self) -> Vec2d<T> {
+        let old_width = self.width;
+        let new_width = self.height();
+        let new_height = old_width;
+        let mut tiles = Vec::with_capacity(self.tiles.len());
+        for ny in 0..new_height {
+            for nx in 0..new_width {
+                tiles.push(self[(old_width - 1 - ny, nx)].clone());
+            }
+        }
+        Vec2d { tiles, width: new_width }
+    }
+
+    /// Mirrors the grid left-to-right.
+    /// # Examples
+    /// ```
+    /// let board = board::Vec2d::new_from_vec(vec![1, 2, 3, 4, 5, 6], 2).unwrap();
+    /// let flipped = board.flip_horizontal();
+    /// assert_eq!(flipped.to_vec(), vec![2, 1, 4, 3, 6, 5]);
+    /// ```
+    pub fn flip_horizontal(&self) -> Vec2d<T> {
+        let width = self.width;
+        let mut tiles = Vec::with_capacity(self.tiles.len());
+        for y in 0..self.height() {
+            for x in 0..width {
+                tiles.push(self[(width - 1 - x, y)].clone());
+            }
+        }
+        Vec2d { tiles, width }
+    }
+
+    /// Mirrors the grid top-to-bottom.
+    pub fn flip_vertical(&self) -> Vec2d<T> {
+        let width = self.width;
+        let height = self.height();
+        let mut tiles = Vec::with_capacity(self.tiles.len());
+        for y in 0..height {
+            for x in 0..width {
+                tiles.push(self[(x, height - 1 - y)].clone());
+            }
+        }
+        Vec2d { tiles, width }
+    }
 }
 
 #[cfg(test)]
@@ -234,6 +397,19 @@ mod tests {
         assert!(board.is_err())
     }
 
+    #[test]
+    fn test_new_from_fn() {
+        let board = Vec2d::new_from_fn(2, 3, |x, y| x + y).unwrap();
+        assert_eq!(board,
+            Vec2d{
+                tiles: vec![0, 1, 1, 2, 2, 3],
+                width: 2
+            }
+        );
+        let board = Vec2d::new_from_fn(0, 2, |x, y| x + y);
+        assert!(board.is_err())
+    }
+
     #[test]
     fn test_get() {
         let board = Vec2d::new('a', 2, 3).unwrap();
@@ -254,4 +430,54 @@ mod tests {
         assert_eq!(board.get_mut(1, 3), None);
     }
 
+    #[test]
+    fn test_try_index() {
+        let board = Vec2d::new('a', 2, 3).unwrap();
+        assert_eq!(board.try_index((1, 2)).unwrap(), &'a');
+        assert!(board.try_index((2, 0)).is_err());
+        assert!(board.try_index((0, 3)).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds_y() {
+        let board = Vec2d::new('a', 2, 3).unwrap();
+        let _ = board[(0, 3)];
+    }
+
+    #[test]
+    fn test_get_col() {
+        let board = Vec2d::new_from_vec(vec![1, 2, 3, 4, 5, 6], 2).unwrap();
+        assert_eq!(board.get_col(1), Some(vec![&2, &4, &6]));
+        assert_eq!(board.get_col(2), None);
+    }
+
+    #[test]
+    fn test_rotate_cw() {
+        let board = Vec2d::new_from_vec(vec![1, 2, 3, 4], 2).unwrap();
+        let rotated = board.rotate_cw();
+        assert_eq!(rotated, Vec2d::new_from_vec(vec![3, 1, 4, 2], 2).unwrap());
+    }
+
+    #[test]
+    fn test_rotate_ccw() {
+        let board = Vec2d::new_from_vec(vec![1, 2, 3, 4], 2).unwrap();
+        let rotated = board.rotate_ccw();
+        assert_eq!(rotated, Vec2d::new_from_vec(vec![2, 4, 1, 3], 2).unwrap());
+    }
+
+    #[test]
+    fn test_flip_horizontal() {
+        let board = Vec2d::new_from_vec(vec![1, 2, 3, 4, 5, 6], 2).unwrap();
+        let flipped = board.flip_horizontal();
+        assert_eq!(flipped, Vec2d::new_from_vec(vec![2, 1, 4, 3, 6, 5], 2).unwrap());
+    }
+
+    #[test]
+    fn test_flip_vertical() {
+        let board = Vec2d::new_from_vec(vec![1, 2, 3, 4, 5, 6], 2).unwrap();
+        let flipped = board.flip_vertical();
+        assert_eq!(flipped, Vec2d::new_from_vec(vec![5, 6, 3, 4, 1, 2], 2).unwrap());
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,197 @@
+//! Numeric matrix operations for `Vec2d<T>`, enabled by the `numeric` feature.
+//!
+//! These are built on the standard `std::ops` traits rather than a numeric
+//! crate, so any `T: Clone` that already supports the relevant operator works.
+//! The element-wise `+` and `-` operators return a `Result` instead of
+//! panicking because two `Vec2d`s with different dimensions have no sensible
+//! element-wise result. `+=` and `-=` cannot return a value, so they panic
+//! on a dimension mismatch.
+
+use crate::{Vec2d, Vec2dError};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+impl<T: Clone> Vec2d<T> {
+    /// Returns a copy of the grid with row `row` and column `col` removed,
+    /// used for determinant/cofactor calculations.
+    /// # Examples
+    /// ```
+    /// let board = board::Vec2d::new_from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3).unwrap();
+    /// let minor = board.minor(1, 1).unwrap();
+    /// assert_eq!(minor.to_vec(), vec![1, 3, 7, 9]);
+    /// ```
+    pub fn minor(&self, row: usize, col: usize) -> Result<Vec2d<T>, Vec2dError> {
+        if row >= self.height() || col >= self.width {
+            return Err(Vec2dError::OutOfBounds {
+                pos: (col, row),
+                width: self.width,
+                height: self.height(),
+            });
+        }
+
+        let tiles: Vec<T> = self
+            .iter_with_pos()
+            .filter(|((x, y), _)| *y != row && *x != col)
+            .map(|(_, tile)| tile.clone())
+            .collect();
+
+        Vec2d::new_from_vec(tiles, self.width.saturating_sub(1))
+    }
+}
+
+fn check_dimensions<T: Clone, U: Clone>(a: &Vec2d<T>, b: &Vec2d<U>) -> Result<(), Vec2dError> {
+    if a.width() != b.width() || a.height() != b.height() {
+        Err(Vec2dError::DimensionMismatch {
+            width: a.width(),
+            height: a.height(),
+            other_width: b.width(),
+            other_height: b.height(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+impl<T: Clone + Add<Output = T>> Add for Vec2d<T> {
+    type Output = Result<Vec2d<T>, Vec2dError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        check_dimensions(&self, &rhs)?;
+        let width = self.width();
+        let tiles = self
+            .to_vec()
+            .into_iter()
+            .zip(rhs.to_vec())
+            .map(|(a, b)| a + b)
+            .collect();
+        Vec2d::new_from_vec(tiles, width)
+    }
+}
+
+impl<T: Clone + Sub<Output = T>> Sub for Vec2d<T> {
+    type Output = Result<Vec2d<T>, Vec2dError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        check_dimensions(&self, &rhs)?;
+        let width = self.width();
+        let tiles = self
+            .to_vec()
+            .into_iter()
+            .zip(rhs.to_vec())
+            .map(|(a, b)| a - b)
+            .collect();
+        Vec2d::new_from_vec(tiles, width)
+    }
+}
+
+impl<T: Clone + AddAssign> AddAssign for Vec2d<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        check_dimensions(self, &rhs).unwrap_or_else(|e| panic!("{}", e));
+        self.tiles
+            .iter_mut()
+            .zip(rhs.to_vec())
+            .for_each(|(a, b)| *a += b);
+    }
+}
+
+impl<T: Clone + SubAssign> SubAssign for Vec2d<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        check_dimensions(self, &rhs).unwrap_or_else(|e| panic!("{}", e));
+        self.tiles
+            .iter_mut()
+            .zip(rhs.to_vec())
+            .for_each(|(a, b)| *a -= b);
+    }
+}
+
+impl<T: Clone + Neg<Output = T>> Neg for Vec2d<T> {
+    type Output = Vec2d<T>;
+
+    fn neg(self) -> Self::Output {
+        let width = self.width();
+        let tiles = self.to_vec().into_iter().map(|t| -t).collect();
+        Vec2d { tiles, width }
+    }
+}
+
+impl<T: Clone + Mul<Output = T>> Mul<T> for Vec2d<T> {
+    type Output = Vec2d<T>;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        let width = self.width();
+        let tiles = self
+            .to_vec()
+            .into_iter()
+            .map(|t| t * scalar.clone())
+            .collect();
+        Vec2d { tiles, width }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minor() {
+        let board = Vec2d::new_from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3).unwrap();
+        let minor = board.minor(1, 1).unwrap();
+        assert_eq!(minor, Vec2d::new_from_vec(vec![1, 3, 7, 9], 2).unwrap());
+        assert!(board.minor(3, 0).is_err());
+        assert!(board.minor(0, 3).is_err());
+    }
+
+    #[test]
+    fn test_add() {
+        let a = Vec2d::new_from_vec(vec![1, 2, 3, 4], 2).unwrap();
+        let b = Vec2d::new_from_vec(vec![5, 6, 7, 8], 2).unwrap();
+        let sum = (a.clone() + b.clone()).unwrap();
+        assert_eq!(sum, Vec2d::new_from_vec(vec![6, 8, 10, 12], 2).unwrap());
+
+        let mismatched = Vec2d::new_from_vec(vec![1, 2, 3], 3).unwrap();
+        assert!((a + mismatched).is_err());
+    }
+
+    #[test]
+    fn test_sub() {
+        let a = Vec2d::new_from_vec(vec![5, 6, 7, 8], 2).unwrap();
+        let b = Vec2d::new_from_vec(vec![1, 2, 3, 4], 2).unwrap();
+        let diff = (a.clone() - b.clone()).unwrap();
+        assert_eq!(diff, Vec2d::new_from_vec(vec![4, 4, 4, 4], 2).unwrap());
+
+        let mismatched = Vec2d::new_from_vec(vec![1, 2, 3], 3).unwrap();
+        assert!((a - mismatched).is_err());
+    }
+
+    #[test]
+    fn test_add_assign() {
+        let mut a = Vec2d::new_from_vec(vec![1, 2, 3, 4], 2).unwrap();
+        a += Vec2d::new_from_vec(vec![5, 6, 7, 8], 2).unwrap();
+        assert_eq!(a, Vec2d::new_from_vec(vec![6, 8, 10, 12], 2).unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_assign_dimension_mismatch() {
+        let mut a = Vec2d::new_from_vec(vec![1, 2, 3, 4], 2).unwrap();
+        a += Vec2d::new_from_vec(vec![1, 2, 3], 3).unwrap();
+    }
+
+    #[test]
+    fn test_sub_assign() {
+        let mut a = Vec2d::new_from_vec(vec![5, 6, 7, 8], 2).unwrap();
+        a -= Vec2d::new_from_vec(vec![1, 2, 3, 4], 2).unwrap();
+        assert_eq!(a, Vec2d::new_from_vec(vec![4, 4, 4, 4], 2).unwrap());
+    }
+
+    #[test]
+    fn test_neg() {
+        let board = Vec2d::new_from_vec(vec![1, -2, 3, -4], 2).unwrap();
+        assert_eq!(-board, Vec2d::new_from_vec(vec![-1, 2, -3, 4], 2).unwrap());
+    }
+
+    #[test]
+    fn test_mul_scalar() {
+        let board = Vec2d::new_from_vec(vec![1, 2, 3, 4], 2).unwrap();
+        assert_eq!(board * 3, Vec2d::new_from_vec(vec![3, 6, 9, 12], 2).unwrap());
+    }
+}
@@ -0,0 +1,114 @@
+//! A rectangular region over a `Vec2d`, e.g. a camera viewport over a larger board.
+
+use crate::{Pos, Vec2d, Vec2dError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    pub fn new(x: usize, y: usize, width: usize, height: usize) -> Rect {
+        Rect { x, y, width, height }
+    }
+
+    /// Returns `true` if `pos` falls within this rectangle.
+    pub fn contains(&self, pos: Pos) -> bool {
+        let (x, y) = pos;
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+}
+
+impl<T: Clone> Vec2d<T> {
+    /// Returns a new, owned `Vec2d` containing only the tiles inside `rect`.
+    /// # Examples
+    /// ```
+    /// let board = board::Vec2d::new_from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3).unwrap();
+    /// let rect = board::Rect::new(1, 1, 2, 2);
+    /// let cropped = board.crop(rect).unwrap();
+    /// assert_eq!(cropped.to_vec(), vec![5, 6, 8, 9]);
+    /// ```
+    pub fn crop(&self, rect: Rect) -> Result<Vec2d<T>, Vec2dError> {
+        if rect.width == 0
+            || rect.height == 0
+            || rect.x + rect.width > self.width
+            || rect.y + rect.height > self.height()
+        {
+            return Err(Vec2dError::RectOutOfBounds {
+                rect,
+                width: self.width,
+                height: self.height(),
+            });
+        }
+
+        let tiles: Vec<T> = self.iter_region(rect).map(|(_, tile)| tile.clone()).collect();
+        Ok(Vec2d { tiles, width: rect.width })
+    }
+
+    /// Iterate over `((x, y), &Tile)` for the tiles inside `rect`, visiting
+    /// only the rows and columns `rect` intersects rather than scanning the
+    /// whole grid, so a small viewport over a large board stays cheap.
+    pub fn iter_region(&self, rect: Rect) -> impl Iterator<Item = (Pos, &T)> {
+        let width = self.width;
+        let y_end = (rect.y + rect.height).min(self.height());
+        let x_end = (rect.x + rect.width).min(width);
+        let x_start = rect.x.min(x_end);
+        let y_start = rect.y.min(y_end);
+
+        (y_start..y_end).flat_map(move |y| {
+            let row = &self.tiles[y * width + x_start..y * width + x_end];
+            row.iter().enumerate().map(move |(i, tile)| ((x_start + i, y), tile))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains() {
+        let rect = Rect::new(1, 1, 2, 2);
+        assert!(rect.contains((1, 1)));
+        assert!(rect.contains((2, 2)));
+        assert!(!rect.contains((0, 0)));
+        assert!(!rect.contains((3, 1)));
+        assert!(!rect.contains((1, 3)));
+    }
+
+    #[test]
+    fn test_crop() {
+        let board = Vec2d::new_from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3).unwrap();
+        let cropped = board.crop(Rect::new(1, 1, 2, 2)).unwrap();
+        assert_eq!(cropped, Vec2d::new_from_vec(vec![5, 6, 8, 9], 2).unwrap());
+
+        assert!(board.crop(Rect::new(2, 2, 2, 2)).is_err());
+        assert!(board.crop(Rect::new(0, 0, 0, 1)).is_err());
+    }
+
+    #[test]
+    fn test_iter_region() {
+        let board = Vec2d::new_from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3).unwrap();
+        let region: Vec<_> = board.iter_region(Rect::new(1, 1, 2, 2))
+            .map(|(_, tile)| *tile)
+            .collect();
+        assert_eq!(region, vec![5, 6, 8, 9]);
+    }
+
+    #[test]
+    fn test_iter_region_clamps_to_grid() {
+        let board = Vec2d::new_from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], 3).unwrap();
+        let region: Vec<_> = board.iter_region(Rect::new(2, 2, 5, 5))
+            .map(|(_, tile)| *tile)
+            .collect();
+        assert_eq!(region, vec![9]);
+
+        let region: Vec<_> = board.iter_region(Rect::new(3, 3, 2, 2))
+            .map(|(_, tile)| *tile)
+            .collect();
+        assert!(region.is_empty());
+    }
+}
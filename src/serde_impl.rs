@@ -0,0 +1,106 @@
+//! `serde` support for `Vec2d`, enabled by the `serde` feature.
+//!
+//! `Serialize` is derived in spirit (a `{ width, tiles }` struct), but
+//! `Deserialize` is hand-written: a derived impl could construct a `Vec2d`
+//! whose `tiles.len()` isn't divisible by `width`, breaking the invariant
+//! every other method relies on. Instead we reuse `new_from_vec`, which
+//! performs the same checks, and surface its `Vec2dError` as a `serde` error.
+
+use crate::Vec2d;
+use serde::de::{self, Deserializer, MapAccess, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::marker::PhantomData;
+
+impl<T: Serialize> Serialize for Vec2d<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Vec2d", 2)?;
+        state.serialize_field("width", &self.width)?;
+        state.serialize_field("tiles", &self.tiles)?;
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Width,
+    Tiles,
+}
+
+struct Vec2dVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for Vec2dVisitor<T> {
+    type Value = Vec2d<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a struct with fields `width` and `tiles`")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut width = None;
+        let mut tiles: Option<Vec<T>> = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                Field::Width => {
+                    if width.is_some() {
+                        return Err(de::Error::duplicate_field("width"));
+                    }
+                    width = Some(map.next_value()?);
+                }
+                Field::Tiles => {
+                    if tiles.is_some() {
+                        return Err(de::Error::duplicate_field("tiles"));
+                    }
+                    tiles = Some(map.next_value()?);
+                }
+            }
+        }
+        let width = width.ok_or_else(|| de::Error::missing_field("width"))?;
+        let tiles = tiles.ok_or_else(|| de::Error::missing_field("tiles"))?;
+        Vec2d::new_from_vec(tiles, width).map_err(de::Error::custom)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vec2d<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("Vec2d", &["width", "tiles"], Vec2dVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let board = Vec2d::new_from_vec(vec!['a', 'b', 'c', 'd'], 2).unwrap();
+        let json = serde_json::to_string(&board).unwrap();
+        let deserialized: Vec2d<char> = serde_json::from_str(&json).unwrap();
+        assert_eq!(board, deserialized);
+    }
+
+    #[test]
+    fn test_deserialize_not_divisible_by_width() {
+        let json = r#"{"width":2,"tiles":["a","b","c"]}"#;
+        let result: Result<Vec2d<char>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_deserialize_width_or_input_len_is_0() {
+        let json = r#"{"width":0,"tiles":["a","b"]}"#;
+        let result: Result<Vec2d<char>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+}
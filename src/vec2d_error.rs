@@ -1,3 +1,4 @@
+use crate::{Pos, Rect};
 use std::fmt::Display;
 
 #[derive(Debug)]
@@ -5,6 +6,9 @@ pub enum Vec2dError {
     WidthOrHeightIs0 { width: usize, height: usize },
     WidthOrInputLenIs0 { width: usize, input_len: usize },
     InputNotDivisibleByWidth { width: usize, input_len: usize },
+    DimensionMismatch { width: usize, height: usize, other_width: usize, other_height: usize },
+    RectOutOfBounds { rect: Rect, width: usize, height: usize },
+    OutOfBounds { pos: Pos, width: usize, height: usize },
 }
 
 impl Display for Vec2dError {
@@ -26,6 +30,15 @@ impl Display for Vec2dError {
             },
             Vec2dError::InputNotDivisibleByWidth { width, input_len } => {
                 write!(f, "The input_len is not divisible by the width: {} % {} = {}", input_len, width, input_len % width)
+            },
+            Vec2dError::DimensionMismatch { width, height, other_width, other_height } => {
+                write!(f, "Dimensions do not match: width: {}, height: {}, other width: {}, other height: {}", width, height, other_width, other_height)
+            },
+            Vec2dError::RectOutOfBounds { rect, width, height } => {
+                write!(f, "Rect {:?} falls outside of the grid bounds. Width: {}, height: {}", rect, width, height)
+            },
+            Vec2dError::OutOfBounds { pos, width, height } => {
+                write!(f, "Tried to index with pos: {:?}, with width: {}, height: {}", pos, width, height)
             }
         }
     }